@@ -6,7 +6,8 @@
 use pgrx::prelude::*;
 
 use crate::distance::{
-    cosine_distance, euclidean_distance, inner_product_distance, manhattan_distance,
+    cosine_distance, euclidean_distance, hamming_distance, inner_product_distance,
+    jaccard_distance, manhattan_distance,
 };
 
 // ============================================================================
@@ -89,6 +90,54 @@ pub fn l1_distance_arr(a: Vec<f32>, b: Vec<f32>) -> f32 {
     manhattan_distance(&a, &b)
 }
 
+// ============================================================================
+// Binary Distance Functions (bytea-based) with Hardware Popcount
+// ============================================================================
+
+/// Compute Hamming distance between two bit-packed binary vectors
+/// (popcount of `a XOR b`, summed across bytes). Uses hardware popcount automatically.
+#[pg_extern(immutable, parallel_safe)]
+pub fn hamming_distance_arr(a: Vec<u8>, b: Vec<u8>) -> i64 {
+    if a.len() != b.len() {
+        pgrx::error!(
+            "Cannot compute distance between binary vectors of different lengths ({} vs {})",
+            a.len(),
+            b.len()
+        );
+    }
+    hamming_distance(&a, &b)
+}
+
+/// Compute Jaccard/Tanimoto distance between two bit-packed binary vectors:
+/// `1 - popcount(a AND b) / popcount(a OR b)`, defined as 0 when both are all-zero.
+/// Uses hardware popcount automatically.
+#[pg_extern(immutable, parallel_safe)]
+pub fn jaccard_distance_arr(a: Vec<u8>, b: Vec<u8>) -> f32 {
+    if a.len() != b.len() {
+        pgrx::error!(
+            "Cannot compute distance between binary vectors of different lengths ({} vs {})",
+            a.len(),
+            b.len()
+        );
+    }
+    jaccard_distance(&a, &b)
+}
+
+/// Quantize a float vector into a bit-packed binary vector, for coarse pre-filtering
+/// with [`hamming_distance_arr`] or [`jaccard_distance_arr`]. Each component is
+/// thresholded at 0 (positive -> 1, non-positive -> 0) and 8 dims are packed per byte.
+#[pg_extern(immutable, parallel_safe)]
+pub fn quantize_binary(v: Vec<f32>) -> Vec<u8> {
+    v.chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, &x)| byte | ((x > 0.0) as u8) << i)
+        })
+        .collect()
+}
+
 // ============================================================================
 // Vector Utility Functions
 // ============================================================================
@@ -198,6 +247,37 @@ mod tests {
         assert!((dist - 12.0).abs() < 1e-5);
     }
 
+    #[pg_test]
+    fn test_hamming_distance() {
+        let a = vec![0b1010_1010u8];
+        let b = vec![0b1111_0000u8];
+        // XOR = 0b0101_1010 -> popcount = 4
+        assert_eq!(hamming_distance_arr(a, b), 4);
+    }
+
+    #[pg_test]
+    fn test_jaccard_distance() {
+        let a = vec![0b1111_0000u8];
+        let b = vec![0b1010_0000u8];
+        // AND popcount = 2, OR popcount = 4 -> distance = 1 - 2/4 = 0.5
+        let dist = jaccard_distance_arr(a, b);
+        assert!((dist - 0.5).abs() < 1e-5);
+    }
+
+    #[pg_test]
+    fn test_jaccard_distance_all_zero() {
+        let a = vec![0u8];
+        let b = vec![0u8];
+        assert_eq!(jaccard_distance_arr(a, b), 0.0);
+    }
+
+    #[pg_test]
+    fn test_quantize_binary() {
+        let v = vec![1.0, -1.0, 0.5, -0.5, 0.0, 2.0, -2.0, 0.1];
+        let packed = quantize_binary(v);
+        assert_eq!(packed, vec![0b1010_0101u8]);
+    }
+
     #[pg_test]
     fn test_simd_various_sizes() {
         // Test various sizes to ensure SIMD remainder handling works
@@ -2,14 +2,385 @@
 
 use crate::{Embedder, EmbeddingError, Result};
 use ruvector_core::{
-    Distance, HnswConfig, IndexBuilder, MemoryStore, SearchParams,
-    VectorEntry, VectorId, VectorIndex,
+    Distance, HnswConfig, IndexBuilder, MemoryStore, SearchParams, VectorEntry, VectorId,
+    VectorIndex,
 };
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, info, instrument};
 
+/// BM25 term frequency saturation parameter
+const BM25_K1: f32 = 1.2;
+/// BM25 length normalization parameter
+const BM25_B: f32 = 0.75;
+/// Reciprocal Rank Fusion smoothing constant
+const RRF_C: f32 = 60.0;
+
+/// Split text into lowercase alphanumeric tokens for the keyword index
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase())
+        .collect()
+}
+
+/// In-memory inverted index used for the keyword half of hybrid search
+#[derive(Debug, Default)]
+struct Bm25Index {
+    /// term -> (doc id -> term frequency)
+    postings: HashMap<String, HashMap<VectorId, u32>>,
+    /// doc id -> token count, used for length normalization
+    doc_lens: HashMap<VectorId, usize>,
+    /// sum of all doc lengths, for avgdl
+    total_len: usize,
+}
+
+impl Bm25Index {
+    fn insert(&mut self, id: VectorId, text: &str) {
+        let tokens = tokenize(text);
+        self.doc_lens.insert(id, tokens.len());
+        self.total_len += tokens.len();
+        for token in tokens {
+            *self
+                .postings
+                .entry(token)
+                .or_default()
+                .entry(id)
+                .or_insert(0) += 1;
+        }
+    }
+
+    fn remove(&mut self, id: VectorId) {
+        if let Some(len) = self.doc_lens.remove(&id) {
+            self.total_len = self.total_len.saturating_sub(len);
+        }
+        for postings in self.postings.values_mut() {
+            postings.remove(&id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.postings.clear();
+        self.doc_lens.clear();
+        self.total_len = 0;
+    }
+
+    fn avgdl(&self) -> f32 {
+        if self.doc_lens.is_empty() {
+            0.0
+        } else {
+            self.total_len as f32 / self.doc_lens.len() as f32
+        }
+    }
+
+    /// Rank documents against a query using Okapi BM25, highest score first
+    fn search(&self, query: &str, k: usize) -> Vec<(VectorId, f32)> {
+        let n = self.doc_lens.len();
+        if n == 0 {
+            return Vec::new();
+        }
+        let avgdl = self.avgdl().max(1.0);
+        let mut scores: HashMap<VectorId, f32> = HashMap::new();
+
+        for term in tokenize(query) {
+            let Some(postings) = self.postings.get(&term) else {
+                continue;
+            };
+            let n_t = postings.len();
+            let idf = ((n as f32 - n_t as f32 + 0.5) / (n_t as f32 + 0.5) + 1.0).ln();
+
+            for (&id, &f_td) in postings {
+                let f_td = f_td as f32;
+                let doc_len = self.doc_lens.get(&id).copied().unwrap_or(0) as f32;
+                let denom = f_td + BM25_K1 * (1.0 - BM25_B + BM25_B * doc_len / avgdl);
+                *scores.entry(id).or_insert(0.0) += idf * (f_td * (BM25_K1 + 1.0)) / denom;
+            }
+        }
+
+        let mut ranked: Vec<(VectorId, f32)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked.truncate(k);
+        ranked
+    }
+}
+
+/// Vector storage mode, describing how [`RuVectorEmbeddings::encode_vector`] compresses a
+/// vector for a caller who wants to store or transmit it outside this index
+///
+/// This does **not** reduce the memory used by the index itself: `ruvector_core`'s
+/// `MemoryStore` holds the full-precision vectors used for search and HNSW graph traversal,
+/// and exposes no quantized storage backend for this crate to plug into, so every inserted
+/// vector is still kept at full precision regardless of this setting. [`RuVectorEmbeddings`]
+/// learns the quantization parameters as vectors are inserted purely so `encode_vector` has
+/// something to encode against; see its docs before assuming this shrinks RAM usage.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Quantization {
+    /// No compression
+    #[default]
+    None,
+    /// Int8 scalar quantization: per-dimension min/max learned over inserted vectors,
+    /// mapping each float to `round((x - min) / (max - min) * 255)`
+    Scalar,
+    /// Product quantization: the vector is split into `m` subvectors, each quantized
+    /// against a `2^bits`-centroid codebook trained with k-means
+    Pq { m: usize, bits: usize },
+}
+
+/// Per-dimension `[min, max]` learned for int8 scalar quantization
+#[derive(Debug, Clone)]
+struct ScalarParams {
+    min: Vec<f32>,
+    max: Vec<f32>,
+}
+
+impl ScalarParams {
+    fn new(dim: usize) -> Self {
+        Self {
+            min: vec![f32::INFINITY; dim],
+            max: vec![f32::NEG_INFINITY; dim],
+        }
+    }
+
+    /// Fold a newly inserted vector into the running per-dimension min/max
+    fn observe(&mut self, v: &[f32]) {
+        for (i, &x) in v.iter().enumerate() {
+            self.min[i] = self.min[i].min(x);
+            self.max[i] = self.max[i].max(x);
+        }
+    }
+
+    /// `round((x - min) / (max - min) * 255)` per dimension
+    fn encode(&self, v: &[f32]) -> Vec<u8> {
+        v.iter()
+            .enumerate()
+            .map(|(i, &x)| {
+                let (min, max) = (self.min[i], self.max[i]);
+                if max <= min {
+                    0
+                } else {
+                    (((x - min) / (max - min)) * 255.0).round().clamp(0.0, 255.0) as u8
+                }
+            })
+            .collect()
+    }
+
+    /// Inverse of [`Self::encode`]
+    fn decode(&self, codes: &[u8]) -> Vec<f32> {
+        codes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| self.min[i] + (c as f32 / 255.0) * (self.max[i] - self.min[i]))
+            .collect()
+    }
+}
+
+/// Product-quantization codebook: `m` subspaces, up to `2^bits` centroids each, trained
+/// with a bounded k-means over a sample of inserted vectors
+#[derive(Debug, Clone)]
+struct PqCodebook {
+    sub_dim: usize,
+    /// `centroids[subspace][centroid_index]`
+    centroids: Vec<Vec<Vec<f32>>>,
+}
+
+impl PqCodebook {
+    const KMEANS_ITERS: usize = 8;
+    const TRAIN_SAMPLE_CAP: usize = 2000;
+
+    fn fit(sample: &[Vec<f32>], dim: usize, m: usize, bits: usize) -> Self {
+        let m = m.max(1);
+        let sub_dim = dim.div_ceil(m);
+        let k = (1usize << bits.min(8)).max(1);
+
+        let centroids = (0..m)
+            .map(|s| {
+                let start = s * sub_dim;
+                let end = (start + sub_dim).min(dim);
+                let subs: Vec<Vec<f32>> = sample.iter().map(|v| v[start..end].to_vec()).collect();
+                kmeans(&subs, k, Self::KMEANS_ITERS)
+            })
+            .collect();
+
+        Self { sub_dim, centroids }
+    }
+
+    /// Encode `v` as one centroid index per subspace
+    fn encode(&self, v: &[f32]) -> Vec<u8> {
+        self.centroids
+            .iter()
+            .enumerate()
+            .map(|(s, centroids)| {
+                let start = s * self.sub_dim;
+                let end = (start + self.sub_dim).min(v.len());
+                nearest_centroid(&v[start..end], centroids) as u8
+            })
+            .collect()
+    }
+}
+
+fn kmeans(points: &[Vec<f32>], k: usize, iters: usize) -> Vec<Vec<f32>> {
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let k = k.min(points.len());
+    let stride = (points.len() / k).max(1);
+    let mut centroids: Vec<Vec<f32>> = points.iter().step_by(stride).take(k).cloned().collect();
+
+    for _ in 0..iters {
+        let dim = centroids[0].len();
+        let mut sums = vec![vec![0.0f32; dim]; centroids.len()];
+        let mut counts = vec![0usize; centroids.len()];
+
+        for p in points {
+            let idx = nearest_centroid(p, &centroids);
+            for (d, &x) in p.iter().enumerate() {
+                sums[idx][d] += x;
+            }
+            counts[idx] += 1;
+        }
+
+        for (c, (sum, count)) in centroids.iter_mut().zip(sums.iter().zip(counts.iter())) {
+            if *count > 0 {
+                for (d, s) in sum.iter().enumerate() {
+                    c[d] = s / *count as f32;
+                }
+            }
+        }
+    }
+
+    centroids
+}
+
+fn nearest_centroid(point: &[f32], centroids: &[Vec<f32>]) -> usize {
+    centroids
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            sq_dist(point, a)
+                .partial_cmp(&sq_dist(point, b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+fn sq_dist(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum()
+}
+
+/// Runtime quantization state: running scalar params, or a PQ codebook trained lazily
+/// once enough vectors have been observed
+enum Quantizer {
+    None,
+    Scalar(ScalarParams),
+    Pq {
+        m: usize,
+        bits: usize,
+        sample: Vec<Vec<f32>>,
+        codebook: Option<PqCodebook>,
+    },
+}
+
+impl Quantizer {
+    /// Minimum samples collected before a PQ codebook is trained
+    const PQ_MIN_TRAIN_SAMPLES: usize = 64;
+
+    fn new(mode: Quantization, dim: usize) -> Self {
+        match mode {
+            Quantization::None => Quantizer::None,
+            Quantization::Scalar => Quantizer::Scalar(ScalarParams::new(dim)),
+            Quantization::Pq { m, bits } => Quantizer::Pq {
+                m,
+                bits,
+                sample: Vec::new(),
+                codebook: None,
+            },
+        }
+    }
+
+    /// Fold a newly inserted vector into the quantizer's training state
+    fn observe(&mut self, dim: usize, v: &[f32]) {
+        match self {
+            Quantizer::None => {}
+            Quantizer::Scalar(params) => params.observe(v),
+            Quantizer::Pq {
+                m,
+                bits,
+                sample,
+                codebook,
+            } => {
+                if sample.len() < PqCodebook::TRAIN_SAMPLE_CAP {
+                    sample.push(v.to_vec());
+                }
+                if codebook.is_none() && sample.len() >= Self::PQ_MIN_TRAIN_SAMPLES {
+                    *codebook = Some(PqCodebook::fit(sample, dim, *m, *bits));
+                }
+            }
+        }
+    }
+
+    /// Whether [`Self::encode`] can produce codes right now: always `true` for
+    /// [`Quantization::None`]/[`Quantization::Scalar`], `true` for [`Quantization::Pq`] once
+    /// its codebook has finished training, `false` before that
+    fn ready(&self) -> bool {
+        match self {
+            Quantizer::None | Quantizer::Scalar(_) => true,
+            Quantizer::Pq { codebook, .. } => codebook.is_some(),
+        }
+    }
+
+    /// Encode `v` under the configured mode, or `None` for [`Quantization::None`] or an
+    /// untrained PQ codebook
+    fn encode(&self, v: &[f32]) -> Option<Vec<u8>> {
+        match self {
+            Quantizer::None => None,
+            Quantizer::Scalar(params) => Some(params.encode(v)),
+            Quantizer::Pq {
+                codebook: Some(cb), ..
+            } => Some(cb.encode(v)),
+            Quantizer::Pq { codebook: None, .. } => None,
+        }
+    }
+
+    /// Decode scalar-quantized codes back into an approximate vector; `None` for any other
+    /// mode, since PQ codes need the trained codebook's centroids to decode and this crate
+    /// doesn't currently reconstruct a full vector from them
+    fn decode(&self, codes: &[u8]) -> Option<Vec<f32>> {
+        match self {
+            Quantizer::Scalar(params) => Some(params.decode(codes)),
+            _ => None,
+        }
+    }
+}
+
+/// Index statistics
+#[derive(Debug, Clone)]
+pub struct IndexStats {
+    /// Number of vectors stored
+    pub len: usize,
+    /// Embedding dimension
+    pub dimension: usize,
+    /// The configured storage mode for [`RuVectorEmbeddings::encode_vector`]
+    pub quantization: Quantization,
+    /// Bytes actually used storing vectors, which are always full-precision `f32` in the
+    /// underlying `MemoryStore` — `quantization` does not reduce this. See
+    /// [`RuVectorEmbeddings::encode_vector`] for an opt-in compressed *export* format that
+    /// does not change the index's own memory footprint.
+    pub raw_bytes: usize,
+    /// Whether `encode_vector` can produce codes right now: always `true` for
+    /// [`Quantization::None`]/[`Quantization::Scalar`]; for [`Quantization::Pq`], `true`
+    /// only once its codebook has finished training on enough inserted vectors
+    pub quantization_ready: bool,
+}
+
 /// RuVector integration for ONNX embeddings
+///
+/// Backed by the in-process [`MemoryStore`]. Use
+/// [`RuVectorEmbeddings::open`]/[`RuVectorEmbeddings::save`], or
+/// [`RuVectorBuilder::build_persistent`], to snapshot an index to disk and reload it later —
+/// see those methods for the persistence format and its limitations.
 pub struct RuVectorEmbeddings {
     /// The embedder for generating vectors
     embedder: Arc<Embedder>,
@@ -17,8 +388,19 @@ pub struct RuVectorEmbeddings {
     index: VectorIndex<MemoryStore>,
     /// Mapping from vector ID to original text
     texts: HashMap<VectorId, String>,
+    /// Keyword index over `texts`, kept in sync on insert/delete for hybrid search
+    bm25: Bm25Index,
+    /// Configured vector storage mode
+    quantization: Quantization,
+    /// Quantization training state, updated as vectors are inserted
+    quantizer: Quantizer,
     /// Index name
     name: String,
+    /// Original config, retained so [`Self::save`] can write a reloadable snapshot
+    config: RuVectorConfig,
+    /// Path set by [`Self::open`]/[`RuVectorBuilder::build_persistent`]; `None` for a
+    /// purely in-memory index, in which case [`Self::save`] errors
+    persist_path: Option<PathBuf>,
 }
 
 /// Search result with text and score
@@ -35,7 +417,7 @@ pub struct SearchResult {
 }
 
 /// Configuration for creating a RuVector index
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuVectorConfig {
     /// Distance metric
     pub distance: Distance,
@@ -45,6 +427,9 @@ pub struct RuVectorConfig {
     pub ef_construction: usize,
     /// Maximum number of elements
     pub max_elements: usize,
+    /// Quantization mode used by [`RuVectorEmbeddings::encode_vector`]; the index itself
+    /// stores every vector at full precision whatever this is set to, so it does not save memory
+    pub quantization: Quantization,
 }
 
 impl Default for RuVectorConfig {
@@ -54,6 +439,7 @@ impl Default for RuVectorConfig {
             m: 16,
             ef_construction: 100,
             max_elements: 100_000,
+            quantization: Quantization::None,
         }
     }
 }
@@ -88,11 +474,18 @@ impl RuVectorEmbeddings {
             .build_memory()
             .map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
 
+        let quantizer = Quantizer::new(config.quantization, dimension);
+
         Ok(Self {
             embedder,
             index,
             texts: HashMap::new(),
+            bm25: Bm25Index::default(),
+            quantization: config.quantization,
+            quantizer,
             name,
+            config,
+            persist_path: None,
         })
     }
 
@@ -100,7 +493,9 @@ impl RuVectorEmbeddings {
     pub fn new_default(name: impl Into<String>, embedder: Arc<Embedder>) -> Result<Self> {
         Self::new(name, embedder, RuVectorConfig::default())
     }
+}
 
+impl RuVectorEmbeddings {
     /// Insert a single text with optional metadata
     #[instrument(skip(self, text, metadata), fields(text_len = text.len()))]
     pub fn insert(
@@ -119,6 +514,8 @@ impl RuVectorEmbeddings {
         embedding: Vec<f32>,
         metadata: Option<HashMap<String, serde_json::Value>>,
     ) -> Result<VectorId> {
+        self.quantizer.observe(embedding.len(), &embedding);
+
         let entry = VectorEntry {
             id: None,
             vector: embedding,
@@ -131,6 +528,8 @@ impl RuVectorEmbeddings {
             .map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
 
         self.texts.insert(id, text.to_string());
+        self.bm25.insert(id, text);
+        self.autosave()?;
 
         debug!("Inserted text with ID {:?}", id);
         Ok(id)
@@ -156,6 +555,10 @@ impl RuVectorEmbeddings {
             ));
         }
 
+        for vector in &embeddings {
+            self.quantizer.observe(vector.len(), vector);
+        }
+
         let entries: Vec<VectorEntry> = embeddings
             .into_iter()
             .map(|vector| VectorEntry {
@@ -172,7 +575,9 @@ impl RuVectorEmbeddings {
 
         for (id, text) in ids.iter().zip(texts.iter()) {
             self.texts.insert(*id, text.as_ref().to_string());
+            self.bm25.insert(*id, text.as_ref());
         }
+        self.autosave()?;
 
         info!("Inserted {} vectors", ids.len());
         Ok(ids)
@@ -219,7 +624,18 @@ impl RuVectorEmbeddings {
         Ok(search_results)
     }
 
-    /// Search with metadata filter
+    /// Search with a metadata filter
+    ///
+    /// **This does not do what the name implies it should**: a metadata predicate belongs
+    /// in the HNSW greedy-layer walk itself (a `SearchParams::filter` field threaded through
+    /// `VectorIndex::search`), so a selective filter still returns the true top-k matches.
+    /// `ruvector_core` exposes no such hook, and adding one is out of scope for this
+    /// integration crate, so this is only an approximation: it over-fetches `k * 4`
+    /// candidates from the unfiltered ANN search and discards non-matching ones post-hoc.
+    /// Under a selective filter this routinely returns fewer than `k` results, or none at
+    /// all if every match for the predicate falls outside the first `k * 4` candidates.
+    /// Documents with no metadata never match (`filter` has nothing to test), so they are
+    /// excluded rather than passed through.
     #[instrument(skip(self, query, filter), fields(k))]
     pub fn search_filtered(
         &self,
@@ -245,11 +661,12 @@ impl RuVectorEmbeddings {
             .filter_map(|r| {
                 let text = self.texts.get(&r.id)?.clone();
 
-                // Apply filter
-                if let Some(ref meta) = r.metadata {
-                    if !filter(meta) {
-                        return None;
-                    }
+                let passes = match r.metadata.as_ref() {
+                    Some(meta) => filter(meta),
+                    None => false,
+                };
+                if !passes {
+                    return None;
                 }
 
                 Some(SearchResult {
@@ -262,6 +679,7 @@ impl RuVectorEmbeddings {
             .take(k)
             .collect();
 
+        debug!("Filtered search returned {} results", filtered.len());
         Ok(filtered)
     }
 
@@ -290,6 +708,8 @@ impl RuVectorEmbeddings {
 
         if deleted {
             self.texts.remove(&id);
+            self.bm25.remove(id);
+            self.autosave()?;
         }
 
         Ok(deleted)
@@ -305,6 +725,40 @@ impl RuVectorEmbeddings {
         self.index.is_empty()
     }
 
+    /// Get index statistics
+    pub fn stats(&self) -> IndexStats {
+        let len = self.index.len();
+        let dimension = self.embedder.dimension();
+        let raw_bytes = len * dimension * std::mem::size_of::<f32>();
+
+        IndexStats {
+            len,
+            dimension,
+            quantization: self.quantization,
+            raw_bytes,
+            quantization_ready: self.quantizer.ready(),
+        }
+    }
+
+    /// Encode `vector` under the configured [`Quantization`] mode, for a caller who wants to
+    /// store or transmit a compressed representation outside this index
+    ///
+    /// Returns `None` for [`Quantization::None`], or if PQ is configured but hasn't yet
+    /// trained its codebook ([`IndexStats::quantization_ready`] reports this). This does
+    /// **not** change how `self` stores `vector` — see [`IndexStats::raw_bytes`].
+    pub fn encode_vector(&self, vector: &[f32]) -> Option<Vec<u8>> {
+        self.quantizer.encode(vector)
+    }
+
+    /// Decode codes produced by [`Self::encode_vector`] back into an approximate vector
+    ///
+    /// Only meaningful for [`Quantization::Scalar`]; returns `None` for any other mode,
+    /// since reconstructing a full vector from PQ codes needs the trained codebook's
+    /// centroids, which this crate doesn't currently expose a decode path for.
+    pub fn decode_vector(&self, codes: &[u8]) -> Option<Vec<f32>> {
+        self.quantizer.decode(codes)
+    }
+
     /// Get index name
     pub fn name(&self) -> &str {
         &self.name
@@ -326,8 +780,199 @@ impl RuVectorEmbeddings {
             .clear()
             .map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
         self.texts.clear();
+        self.bm25.clear();
+        self.quantizer = Quantizer::new(self.quantization, self.embedder.dimension());
+        self.autosave()?;
         Ok(())
     }
+
+    /// Hybrid keyword + semantic search, fusing both rankings with Reciprocal Rank Fusion
+    ///
+    /// `semantic_ratio` weights the vector ranking (`semantic_ratio`) against the BM25
+    /// keyword ranking (`1.0 - semantic_ratio`); a document appearing in only one list is
+    /// still scored from that list alone. A ratio of `1.0` behaves like [`Self::search`],
+    /// a ratio of `0.0` ranks keyword matches first; with fewer than `k` keyword matches the
+    /// remaining slots hold vector-only results with a score of `0.0`.
+    #[instrument(skip(self, query), fields(k, semantic_ratio))]
+    pub fn search_hybrid(
+        &self,
+        query: &str,
+        k: usize,
+        semantic_ratio: f32,
+    ) -> Result<Vec<SearchResult>> {
+        let keyword_ranked = self.bm25.search(query, k * 4);
+
+        let query_embedding = self.embedder.embed_one(query)?;
+        let params = SearchParams {
+            k: k * 4,
+            ef_search: k * 4,
+            ..Default::default()
+        };
+        let vector_ranked = self
+            .index
+            .search(&query_embedding, &params)
+            .map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
+
+        let keyword_weight = 1.0 - semantic_ratio;
+        let vector_weight = semantic_ratio;
+
+        let mut fused: HashMap<VectorId, f32> = HashMap::new();
+        for (rank, (id, _)) in keyword_ranked.iter().enumerate() {
+            *fused.entry(*id).or_insert(0.0) += keyword_weight / (RRF_C + (rank + 1) as f32);
+        }
+        for (rank, result) in vector_ranked.iter().enumerate() {
+            *fused.entry(result.id).or_insert(0.0) += vector_weight / (RRF_C + (rank + 1) as f32);
+        }
+
+        let metadata_by_id: HashMap<VectorId, Option<serde_json::Value>> = vector_ranked
+            .into_iter()
+            .map(|r| (r.id, r.metadata))
+            .collect();
+
+        let mut fused: Vec<(VectorId, f32)> = fused.into_iter().collect();
+        fused.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        fused.truncate(k);
+
+        let search_results = fused
+            .into_iter()
+            .filter_map(|(id, score)| {
+                let text = self.texts.get(&id)?.clone();
+                Some(SearchResult {
+                    id,
+                    text,
+                    score,
+                    metadata: metadata_by_id.get(&id).cloned().flatten(),
+                })
+            })
+            .collect();
+
+        debug!("Hybrid search completed");
+        Ok(search_results)
+    }
+}
+
+/// On-disk snapshot of a [`RuVectorEmbeddings`] index: its config and every stored vector,
+/// text, and metadata blob, reinserted into a fresh in-memory index on [`RuVectorEmbeddings::open`].
+///
+/// `ruvector_core` does not yet expose a durable store, so this crate owns the persistence
+/// format directly rather than depending on one; see [`RuVectorEmbeddings::save`] for the
+/// tradeoffs that come with that.
+#[derive(Serialize, Deserialize)]
+struct PersistedIndex {
+    name: String,
+    config: RuVectorConfig,
+    entries: Vec<PersistedEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PersistedEntry {
+    text: String,
+    vector: Vec<f32>,
+    metadata: Option<serde_json::Value>,
+}
+
+impl RuVectorEmbeddings {
+    /// Open a durable index previously created with [`RuVectorBuilder::build_persistent`]
+    /// or [`Self::save`]
+    ///
+    /// Reads the JSON snapshot at `path` and reinserts every vector into a fresh in-memory
+    /// [`VectorIndex`], rebuilding the HNSW graph and the BM25 keyword index from scratch.
+    #[instrument(skip(embedder), fields(path = %path.as_ref().display()))]
+    pub fn open(path: impl AsRef<Path>, embedder: Arc<Embedder>) -> Result<Self> {
+        let path = path.as_ref();
+        info!("Opening persistent RuVector index at {}", path.display());
+
+        let bytes =
+            std::fs::read(path).map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
+        let snapshot: PersistedIndex =
+            serde_json::from_slice(&bytes).map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
+
+        // `persist_path` is set only after reconstruction finishes: `insert_with_embedding`
+        // autosaves on every call, and re-snapshotting after each of the entries being
+        // reloaded here would be both wasted work and a needless rewrite of the very file
+        // being read.
+        let mut index = Self::new(snapshot.name, embedder, snapshot.config)?;
+
+        for entry in snapshot.entries {
+            index.insert_with_embedding(&entry.text, entry.vector, entry.metadata.map(to_map))?;
+        }
+
+        index.persist_path = Some(path.to_path_buf());
+
+        Ok(index)
+    }
+
+    /// Write every vector, text, and metadata blob to `self`'s persistence path as a single
+    /// JSON snapshot
+    ///
+    /// Called automatically at the end of every [`Self::insert`], [`Self::insert_batch`],
+    /// [`Self::delete`], and [`Self::clear`] on an index opened with a persistence path, so
+    /// each mutation commits a crash-consistent snapshot before returning — a crash right
+    /// after any of those calls returns can lose at most nothing, not the whole session.
+    /// The write itself is atomic (a temp file is written and then renamed over `path`), so
+    /// a crash mid-write can never leave a corrupt snapshot on disk either.
+    ///
+    /// This re-serializes every vector in the index on every call rather than appending an
+    /// incremental transaction, since `ruvector_core` exposes no durable store to commit
+    /// partial writes to: each commit is `O(index size)`, not `O(1)`. A batch insert pays
+    /// this cost once for the whole batch (autosave runs after the batch, not per vector);
+    /// many individual single-vector inserts each pay it in full.
+    pub fn save(&self) -> Result<()> {
+        let path = self.persist_path.as_ref().ok_or_else(|| {
+            EmbeddingError::invalid_config(
+                "index has no persistence path; create it via RuVectorBuilder::build_persistent or RuVectorEmbeddings::open",
+            )
+        })?;
+
+        let mut entries = Vec::with_capacity(self.texts.len());
+        for (id, text) in &self.texts {
+            let stored = self
+                .index
+                .get(*id)
+                .map_err(|e| EmbeddingError::ruvector(e.to_string()))?
+                .ok_or_else(|| EmbeddingError::ruvector(format!("vector {id:?} missing from index")))?;
+            entries.push(PersistedEntry {
+                text: text.clone(),
+                vector: stored.vector,
+                metadata: stored.metadata.map(|m| serde_json::Value::Object(m.into_iter().collect())),
+            });
+        }
+
+        let snapshot = PersistedIndex {
+            name: self.name.clone(),
+            config: self.config.clone(),
+            entries,
+        };
+
+        let json = serde_json::to_vec_pretty(&snapshot)
+            .map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
+
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, json).map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
+        std::fs::rename(&tmp_path, path).map_err(|e| EmbeddingError::ruvector(e.to_string()))?;
+
+        debug!("Saved {} vectors to {}", self.texts.len(), path.display());
+        Ok(())
+    }
+
+    /// Commit a snapshot if this index was opened with a persistence path; a no-op for a
+    /// purely in-memory index
+    fn autosave(&self) -> Result<()> {
+        if self.persist_path.is_some() {
+            self.save()?;
+        }
+        Ok(())
+    }
+}
+
+/// Convert a JSON object value back into the `HashMap<String, Value>` metadata shape used
+/// elsewhere in this crate; non-object values (shouldn't occur for metadata we wrote
+/// ourselves) become an empty map
+fn to_map(value: serde_json::Value) -> HashMap<String, serde_json::Value> {
+    match value {
+        serde_json::Value::Object(map) => map.into_iter().collect(),
+        _ => HashMap::new(),
+    }
 }
 
 /// Builder for creating RuVector indexes
@@ -377,7 +1022,16 @@ impl RuVectorBuilder {
         self
     }
 
-    /// Build the index
+    /// Configure the mode [`RuVectorEmbeddings::encode_vector`] uses to produce a compressed
+    /// export of a vector. The index itself always stores vectors at full precision — see
+    /// [`Quantization`]'s docs for why — so this does not trade away recall or reduce the
+    /// built index's memory use.
+    pub fn with_quantization(mut self, quantization: Quantization) -> Self {
+        self.config.quantization = quantization;
+        self
+    }
+
+    /// Build an in-memory index
     pub fn build(self) -> Result<RuVectorEmbeddings> {
         let embedder = self
             .embedder
@@ -385,18 +1039,247 @@ impl RuVectorBuilder {
 
         RuVectorEmbeddings::new(self.name, embedder, self.config)
     }
+
+    /// Build a durable index that snapshots to `path` via [`RuVectorEmbeddings::save`]
+    ///
+    /// If `path` already holds a snapshot (from a previous run) it is loaded, including all
+    /// previously-inserted vectors; otherwise a fresh index is created and an initial
+    /// (empty) snapshot is written immediately so `path` exists after this call returns.
+    #[instrument(skip(self), fields(name = %self.name, path = %path.as_ref().display()))]
+    pub fn build_persistent(self, path: impl AsRef<Path>) -> Result<RuVectorEmbeddings> {
+        let path = path.as_ref();
+        let embedder = self
+            .embedder
+            .ok_or_else(|| EmbeddingError::invalid_config("Embedder is required"))?;
+
+        if path.exists() {
+            return RuVectorEmbeddings::open(path, embedder);
+        }
+
+        let mut index = RuVectorEmbeddings::new(self.name, embedder, self.config)?;
+        index.persist_path = Some(path.to_path_buf());
+        index.save()?;
+        Ok(index)
+    }
+}
+
+/// A single parsed segment of a [`RagPipeline`] prompt template
+#[derive(Debug, Clone)]
+enum TemplateNode {
+    /// Literal text emitted verbatim
+    Text(String),
+    /// `{{query}}` placeholder
+    Query,
+    /// `{{#each docs}} ... {{/each}}` block, rendered once per retrieved result
+    EachDocs(Vec<DocNode>),
+}
+
+/// A segment inside an `{{#each docs}}` block
+#[derive(Debug, Clone)]
+enum DocNode {
+    /// Literal text emitted verbatim
+    Text(String),
+    /// `{{index}}` — 1-based position of the result
+    Index,
+    /// `{{text}}` — the result's retrieved text
+    DocText,
+    /// `{{score}}` — the result's similarity score
+    Score,
+    /// `{{metadata.<field>}}` — a field read out of the result's metadata JSON
+    Metadata(String),
+}
+
+/// A raw `{{ }}` tag or the literal text between tags
+enum Token {
+    Text(String),
+    Tag(String),
+}
+
+fn tokenize_template(template: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        if start > 0 {
+            tokens.push(Token::Text(rest[..start].to_string()));
+        }
+        rest = &rest[start + 2..];
+        match rest.find("}}") {
+            Some(end) => {
+                tokens.push(Token::Tag(rest[..end].trim().to_string()));
+                rest = &rest[end + 2..];
+            }
+            None => {
+                tokens.push(Token::Tag(rest.trim().to_string()));
+                rest = "";
+            }
+        }
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+    tokens
+}
+
+fn build_nodes(tokens: &[Token], pos: &mut usize) -> Result<Vec<TemplateNode>> {
+    let mut nodes = Vec::new();
+    while *pos < tokens.len() {
+        match &tokens[*pos] {
+            Token::Text(text) => {
+                nodes.push(TemplateNode::Text(text.clone()));
+                *pos += 1;
+            }
+            Token::Tag(tag) if tag == "query" => {
+                nodes.push(TemplateNode::Query);
+                *pos += 1;
+            }
+            Token::Tag(tag) if tag == "#each docs" => {
+                *pos += 1;
+                nodes.push(TemplateNode::EachDocs(build_doc_nodes(tokens, pos)?));
+            }
+            Token::Tag(tag) if tag == "/each" => {
+                return Err(EmbeddingError::invalid_config(
+                    "unbalanced {{/each}}: no matching {{#each docs}}",
+                ));
+            }
+            Token::Tag(tag) => {
+                return Err(EmbeddingError::invalid_config(format!(
+                    "unknown template helper '{{{{{tag}}}}}'"
+                )));
+            }
+        }
+    }
+    Ok(nodes)
+}
+
+fn build_doc_nodes(tokens: &[Token], pos: &mut usize) -> Result<Vec<DocNode>> {
+    let mut nodes = Vec::new();
+    loop {
+        let Some(token) = tokens.get(*pos) else {
+            return Err(EmbeddingError::invalid_config(
+                "unbalanced {{#each docs}}: missing {{/each}}",
+            ));
+        };
+        *pos += 1;
+        match token {
+            Token::Text(text) => nodes.push(DocNode::Text(text.clone())),
+            Token::Tag(tag) => match tag.as_str() {
+                "/each" => return Ok(nodes),
+                "index" => nodes.push(DocNode::Index),
+                "text" => nodes.push(DocNode::DocText),
+                "score" => nodes.push(DocNode::Score),
+                "#each docs" => {
+                    return Err(EmbeddingError::invalid_config(
+                        "nested {{#each docs}} blocks are not supported",
+                    ));
+                }
+                field if field.starts_with("metadata.") => {
+                    nodes.push(DocNode::Metadata(field["metadata.".len()..].to_string()));
+                }
+                other => {
+                    return Err(EmbeddingError::invalid_config(format!(
+                        "unknown template helper '{{{{{other}}}}}'"
+                    )));
+                }
+            },
+        }
+    }
+}
+
+fn metadata_value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Parsed, validated prompt template for [`RagPipeline::format_context`]
+#[derive(Debug, Clone)]
+struct PromptTemplate {
+    nodes: Vec<TemplateNode>,
+}
+
+impl PromptTemplate {
+    /// Parse and validate a template, rejecting unknown helpers and unbalanced blocks
+    fn parse(template: &str) -> Result<Self> {
+        let tokens = tokenize_template(template);
+        let mut pos = 0;
+        let nodes = build_nodes(&tokens, &mut pos)?;
+        Ok(Self { nodes })
+    }
+
+    fn render(&self, query: &str, results: &[SearchResult]) -> String {
+        let mut out = String::new();
+        self.render_nodes(&self.nodes, query, results, &mut out);
+        out
+    }
+
+    fn render_nodes(
+        &self,
+        nodes: &[TemplateNode],
+        query: &str,
+        results: &[SearchResult],
+        out: &mut String,
+    ) {
+        for node in nodes {
+            match node {
+                TemplateNode::Text(text) => out.push_str(text),
+                TemplateNode::Query => out.push_str(query),
+                TemplateNode::EachDocs(body) => {
+                    for (i, result) in results.iter().enumerate() {
+                        Self::render_doc_nodes(body, i, result, out);
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_doc_nodes(nodes: &[DocNode], index: usize, result: &SearchResult, out: &mut String) {
+        for node in nodes {
+            match node {
+                DocNode::Text(text) => out.push_str(text),
+                DocNode::Index => out.push_str(&(index + 1).to_string()),
+                DocNode::DocText => out.push_str(&result.text),
+                DocNode::Score => out.push_str(&result.score.to_string()),
+                DocNode::Metadata(field) => {
+                    if let Some(value) = result.metadata.as_ref().and_then(|m| m.get(field)) {
+                        out.push_str(&metadata_value_to_string(value));
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// RAG (Retrieval-Augmented Generation) helper
+///
+/// Wraps a [`RuVectorEmbeddings`] index, which may be purely in-memory or opened from a
+/// persistent snapshot via [`RuVectorEmbeddings::open`]/[`RuVectorBuilder::build_persistent`].
 pub struct RagPipeline {
     index: RuVectorEmbeddings,
     top_k: usize,
+    template: Option<PromptTemplate>,
 }
 
 impl RagPipeline {
     /// Create a new RAG pipeline
     pub fn new(index: RuVectorEmbeddings, top_k: usize) -> Self {
-        Self { index, top_k }
+        Self {
+            index,
+            top_k,
+            template: None,
+        }
+    }
+
+    /// Configure a custom prompt template used by [`Self::format_context`]
+    ///
+    /// Templates use `{{ }}` interpolation with a `{{#each docs}} ... {{/each}}` loop over
+    /// retrieved results (`{{index}}`, `{{text}}`, `{{score}}`, `{{metadata.<field>}}`) and
+    /// a top-level `{{query}}` placeholder. The template is parsed and validated here —
+    /// unknown helpers or unbalanced blocks return an error immediately rather than at
+    /// query time.
+    pub fn with_template(mut self, template: &str) -> Result<Self> {
+        self.template = Some(PromptTemplate::parse(template)?);
+        Ok(self)
     }
 
     /// Retrieve context for a query
@@ -405,13 +1288,18 @@ impl RagPipeline {
         Ok(results.into_iter().map(|r| r.text).collect())
     }
 
-    /// Format retrieved context as a prompt
+    /// Format retrieved context as a prompt, using the configured template if one was set
+    /// via [`Self::with_template`], or the default `"Context:\n[i] text"` layout otherwise
     pub fn format_context(&self, query: &str) -> Result<String> {
-        let contexts = self.retrieve(query)?;
+        let results = self.index.search(query, self.top_k)?;
+
+        if let Some(template) = &self.template {
+            return Ok(template.render(query, &results));
+        }
 
         let mut prompt = String::from("Context:\n");
-        for (i, ctx) in contexts.iter().enumerate() {
-            prompt.push_str(&format!("[{}] {}\n", i + 1, ctx));
+        for (i, result) in results.iter().enumerate() {
+            prompt.push_str(&format!("[{}] {}\n", i + 1, result.text));
         }
         prompt.push_str(&format!("\nQuestion: {}", query));
 
@@ -436,6 +1324,46 @@ impl RagPipeline {
 
 #[cfg(test)]
 mod tests {
-    // Integration tests would go here
-    // Require running embedder which needs model files
+    // Most of this module's surface needs a running embedder (requires model files), but
+    // PromptTemplate parsing/rendering is pure and testable without one.
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_unknown_helper() {
+        let err = PromptTemplate::parse("hello {{nope}}").unwrap_err();
+        assert!(err.to_string().contains("unknown template helper"));
+    }
+
+    #[test]
+    fn test_parse_rejects_unbalanced_each_open() {
+        let err = PromptTemplate::parse("{{#each docs}}{{text}}").unwrap_err();
+        assert!(err.to_string().contains("missing {{/each}}"));
+    }
+
+    #[test]
+    fn test_parse_rejects_unbalanced_each_close() {
+        let err = PromptTemplate::parse("{{/each}}").unwrap_err();
+        assert!(err.to_string().contains("unbalanced {{/each}}"));
+    }
+
+    #[test]
+    fn test_parse_rejects_nested_each() {
+        let err = PromptTemplate::parse("{{#each docs}}{{#each docs}}{{/each}}{{/each}}")
+            .unwrap_err();
+        assert!(err.to_string().contains("nested"));
+    }
+
+    #[test]
+    fn test_render_substitutes_query_and_literal_text() {
+        let template = PromptTemplate::parse("Q: {{query}}\n").unwrap();
+        assert_eq!(template.render("what is rust?", &[]), "Q: what is rust?\n");
+    }
+
+    #[test]
+    fn test_render_each_docs_empty_contributes_nothing() {
+        let template =
+            PromptTemplate::parse("before{{#each docs}}[{{index}}] {{text}}{{/each}}after")
+                .unwrap();
+        assert_eq!(template.render("q", &[]), "beforeafter");
+    }
 }